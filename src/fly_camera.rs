@@ -0,0 +1,87 @@
+use std::f32::consts::FRAC_PI_2;
+
+use ultraviolet::{mat::Mat4, vec::Vec3};
+
+use crate::Camera;
+
+/// Keep pitch strictly inside +/-90 degrees so the look direction never points straight up or
+/// down, which is where a look-to view matrix loses its sense of "right" and flips.
+const MAX_PITCH: f32 = FRAC_PI_2 - 1e-4;
+
+/// A keyboard/mouse-driven first-person camera, as an alternative to [`ArcballCamera`](crate::ArcballCamera)
+/// for applications that want to fly through a scene instead of orbiting a focus point.
+pub struct FlyCamera {
+    position: Vec3,
+    yaw: f32,
+    pitch: f32,
+    camera: Mat4,
+    inv_camera: Mat4,
+}
+
+impl FlyCamera {
+    /// Create a new fly camera at `position` looking in the direction given by `yaw` and `pitch`
+    /// (both in radians).
+    pub fn new(position: Vec3, yaw: f32, pitch: f32) -> FlyCamera {
+        let mut cam = FlyCamera {
+            position,
+            yaw,
+            pitch: pitch.clamp(-MAX_PITCH, MAX_PITCH),
+            camera: Mat4::identity(),
+            inv_camera: Mat4::identity(),
+        };
+        cam.calc_matrix();
+        cam
+    }
+    /// Get the camera's current position.
+    pub fn position(&self) -> Vec3 {
+        self.position
+    }
+    /// Get the direction the camera is looking in.
+    pub fn look_dir(&self) -> Vec3 {
+        Vec3::new(
+            self.yaw.cos() * self.pitch.cos(),
+            self.pitch.sin(),
+            self.yaw.sin() * self.pitch.cos(),
+        )
+    }
+    /// Move the camera forward/backward along its look direction by `amount`.
+    pub fn move_forward(&mut self, amount: f32) {
+        self.position += self.look_dir() * amount;
+        self.calc_matrix();
+    }
+    /// Move the camera right/left, perpendicular to its look direction and world up, by `amount`.
+    pub fn move_right(&mut self, amount: f32) {
+        let right = self.look_dir().cross(Vec3::unit_y()).normalized();
+        self.position += right * amount;
+        self.calc_matrix();
+    }
+    /// Move the camera up/down along world up by `amount`.
+    pub fn move_up(&mut self, amount: f32) {
+        self.position += Vec3::unit_y() * amount;
+        self.calc_matrix();
+    }
+    /// Apply a mouse look delta, in radians, to the camera's yaw and pitch. Pitch is clamped to
+    /// avoid gimbal flip.
+    pub fn look(&mut self, dx: f32, dy: f32) {
+        self.yaw += dx;
+        self.pitch = (self.pitch + dy).clamp(-MAX_PITCH, MAX_PITCH);
+        self.calc_matrix();
+    }
+    fn calc_matrix(&mut self) {
+        let dir = self.look_dir();
+        self.camera = Mat4::look_at(self.position, self.position + dir, Vec3::unit_y());
+        self.inv_camera = self.camera.inversed();
+    }
+}
+
+impl Camera for FlyCamera {
+    fn view(&self) -> Mat4 {
+        self.camera
+    }
+    fn inv_view(&self) -> Mat4 {
+        self.inv_camera
+    }
+    fn eye_pos(&self) -> Vec3 {
+        self.position
+    }
+}
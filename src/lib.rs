@@ -2,12 +2,48 @@
 //! See the [cube example](https://github.com/Twinklebear/arcball/blob/master/examples/cube.rs) for an example
 //! of use with [glium](https://crates.io/crates/glium).
 
+use std::f32::consts::{FRAC_PI_2, PI};
+use std::time::Instant;
+
 use ultraviolet::{
+    bivec::Bivec3,
     mat::{Mat3, Mat4},
     rotor::Rotor3,
     vec::{Vec2, Vec3, Vec4},
 };
 
+mod fly_camera;
+mod pose;
+mod projection;
+pub use fly_camera::FlyCamera;
+pub use pose::CameraPose;
+pub use projection::{ClipConvention, Projection};
+
+/// A camera that can produce a view matrix and an eye position for rendering.
+///
+/// Implemented by [`ArcballCamera`] and [`FlyCamera`] so render code can be written against
+/// whichever interaction model the application wants without caring which one it is.
+pub trait Camera {
+    /// Get the view matrix computed by the camera.
+    fn view(&self) -> Mat4;
+    /// Get the inverse view matrix.
+    fn inv_view(&self) -> Mat4;
+    /// Get the camera eye position.
+    fn eye_pos(&self) -> Vec3;
+}
+
+impl Camera for ArcballCamera {
+    fn view(&self) -> Mat4 {
+        self.camera
+    }
+    fn inv_view(&self) -> Mat4 {
+        self.inv_camera
+    }
+    fn eye_pos(&self) -> Vec3 {
+        ArcballCamera::eye_pos(self)
+    }
+}
+
 /// The Shoemake Arcball camera.
 pub struct ArcballCamera {
     translation: Mat4,
@@ -17,6 +53,30 @@ pub struct ArcballCamera {
     inv_camera: Mat4,
     zoom_speed: f32,
     inv_screen: [f32; 2],
+    inertia_enabled: bool,
+    inertia_tau: f32,
+    velocity: Rotor3,
+    last_rotate: Option<Instant>,
+    rotation_mode: RotationMode,
+    turntable_pitch: f32,
+}
+
+/// Angular velocity at or below this (radians per second) is treated as stopped and snapped to identity.
+const INERTIA_EPSILON: f32 = 1e-4;
+
+/// Keep turntable pitch strictly inside +/-90 degrees so the view never flips upside down.
+const MAX_TURNTABLE_PITCH: f32 = FRAC_PI_2 - 1e-4;
+
+/// How [`ArcballCamera::rotate`] interprets mouse motion.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum RotationMode {
+    /// The original Shoemake arcball: rotation follows the mouse as if dragging a trackball,
+    /// which can introduce roll.
+    Arcball,
+    /// Azimuth/elevation only: horizontal motion yaws about the world up axis, vertical motion
+    /// pitches about the camera's right axis, and pitch is clamped so the camera never rolls or
+    /// flips upside down.
+    Turntable,
 }
 
 impl ArcballCamera {
@@ -31,6 +91,12 @@ impl ArcballCamera {
             inv_camera: Mat4::identity(),
             zoom_speed,
             inv_screen: [1.0 / screen[0], 1.0 / screen[1]],
+            inertia_enabled: false,
+            inertia_tau: 0.25,
+            velocity: Rotor3::identity(),
+            last_rotate: None,
+            rotation_mode: RotationMode::Arcball,
+            turntable_pitch: 0.0,
         };
         cam.update_camera();
         cam
@@ -66,19 +132,109 @@ impl ArcballCamera {
     /// Rotates from the orientation at the previous mouse position specified by `mouse_prev`
     /// to the orientation at the current mouse position, `mouse_cur`.
     pub fn rotate(&mut self, mouse_prev: Vec2, mouse_cur: Vec2) {
-        let m_cur = Vec2::new(
-            (mouse_cur.x * 2.0 * self.inv_screen[0] - 1.0).clamp(-1.0, 1.0),
-            (1.0 - 2.0 * mouse_cur.y * self.inv_screen[1]).clamp(-1.0, 1.0),
-        );
-        let m_prev = Vec2::new(
-            (mouse_prev.x * 2.0 * self.inv_screen[0] - 1.0).clamp(-1.0, 1.0),
-            (1.0 - 2.0 * mouse_prev.y * self.inv_screen[1]).clamp(-1.0, 1.0),
-        );
-        let mouse_cur_ball = ArcballCamera::screen_to_arcball(m_cur);
-        let mouse_prev_ball = ArcballCamera::screen_to_arcball(m_prev);
-        self.rotation = mouse_cur_ball * mouse_prev_ball * self.rotation;
+        let rotation_before = self.rotation;
+        match self.rotation_mode {
+            RotationMode::Arcball => {
+                let m_cur = Vec2::new(
+                    (mouse_cur.x * 2.0 * self.inv_screen[0] - 1.0).clamp(-1.0, 1.0),
+                    (1.0 - 2.0 * mouse_cur.y * self.inv_screen[1]).clamp(-1.0, 1.0),
+                );
+                let m_prev = Vec2::new(
+                    (mouse_prev.x * 2.0 * self.inv_screen[0] - 1.0).clamp(-1.0, 1.0),
+                    (1.0 - 2.0 * mouse_prev.y * self.inv_screen[1]).clamp(-1.0, 1.0),
+                );
+                let mouse_cur_ball = ArcballCamera::screen_to_arcball(m_cur);
+                let mouse_prev_ball = ArcballCamera::screen_to_arcball(m_prev);
+                self.rotation = mouse_cur_ball * mouse_prev_ball * self.rotation;
+                // Inertia only tracks Arcball drags: the incremental rotor here is a single
+                // rotation about a fixed axis, which is what `update`'s coast assumes. Turntable
+                // composes yaw and pitch about two different axes each frame (see below), which
+                // doesn't reduce to a single coasting rotor without reintroducing roll.
+                if self.inertia_enabled {
+                    let dt = match self.last_rotate {
+                        Some(t) => t.elapsed().as_secs_f32(),
+                        None => 0.0,
+                    };
+                    let incremental = self.rotation * rotation_before.reversed();
+                    self.velocity = if dt > 0.0 {
+                        scale_rotor(incremental, 1.0 / dt)
+                    } else {
+                        Rotor3::identity()
+                    };
+                }
+            }
+            RotationMode::Turntable => {
+                let dx = (mouse_cur.x - mouse_prev.x) * self.inv_screen[0];
+                let dy = (mouse_cur.y - mouse_prev.y) * self.inv_screen[1];
+                let yaw = Rotor3::from_rotation_xz(-dx * PI);
+                let new_pitch = (self.turntable_pitch - dy * PI)
+                    .clamp(-MAX_TURNTABLE_PITCH, MAX_TURNTABLE_PITCH);
+                let pitch_delta = new_pitch - self.turntable_pitch;
+                self.turntable_pitch = new_pitch;
+                // The camera-space X axis is constant across frames, so pitching about it here
+                // (after `self.rotation` is applied) rotates about the camera's *current* right
+                // axis without needing to transform it out to world space first.
+                let pitch = Rotor3::from_rotation_yz(pitch_delta);
+                self.rotation = pitch * self.rotation * yaw;
+            }
+        }
+        self.last_rotate = Some(Instant::now());
         self.update_camera();
     }
+    /// Switch between free Shoemake arcball rotation and up-axis-locked turntable rotation.
+    ///
+    /// Switching into [`RotationMode::Turntable`] derives the starting elevation from the
+    /// camera's current look direction (via [`ArcballCamera::eye_dir`]) so the pitch clamp is
+    /// measured from where the camera actually is, not a false zero baseline; switching when
+    /// already in turntable mode, or into arcball mode, leaves it untouched.
+    pub fn set_rotation_mode(&mut self, mode: RotationMode) {
+        if mode == RotationMode::Turntable && self.rotation_mode != RotationMode::Turntable {
+            self.sync_turntable_pitch();
+            // Arcball inertia doesn't mean anything in Turntable mode; drop it so it can't
+            // resurface as a roll-inducing coast if the caller switches back to Arcball later.
+            self.velocity = Rotor3::identity();
+        }
+        self.rotation_mode = mode;
+    }
+    /// Recompute `turntable_pitch` from the camera's current look direction, so a subsequent
+    /// Turntable drag clamps elevation relative to where `rotation` actually points rather than
+    /// a stale baseline from before `rotation` was last overwritten.
+    fn sync_turntable_pitch(&mut self) {
+        self.turntable_pitch = -self.eye_dir().y.clamp(-1.0, 1.0).asin();
+    }
+    /// Advance any in-flight rotational inertia by `dt` seconds. Has no effect unless
+    /// [`ArcballCamera::set_inertia`] has been used to enable it, and only coasts in
+    /// [`RotationMode::Arcball`] — Turntable rotation is captured as two separate axis rotations
+    /// per frame rather than one, so there is no single rotor to coast without reintroducing
+    /// roll. Call this once per frame while no drag is active to let a flick-and-release gesture
+    /// coast to a stop; the stored angular velocity rotor is applied to `rotation` and decays by
+    /// `exp(-dt / tau)` each call, snapping to zero once its angle drops below a small epsilon.
+    pub fn update(&mut self, dt: f32) {
+        if !self.inertia_enabled
+            || self.rotation_mode != RotationMode::Arcball
+            || rotor_angle(self.velocity) <= INERTIA_EPSILON
+        {
+            return;
+        }
+        let step = scale_rotor(self.velocity, dt);
+        self.rotation = step * self.rotation;
+        let decay = (-dt / self.inertia_tau).exp();
+        self.velocity = scale_rotor(self.velocity, decay);
+        if rotor_angle(self.velocity) <= INERTIA_EPSILON {
+            self.velocity = Rotor3::identity();
+        }
+        self.update_camera();
+    }
+    /// Enable or disable rotational inertia, with `tau` the exponential damping time constant
+    /// (in seconds) that the coasting velocity decays by. Disabled by default, which keeps the
+    /// original instant-stop `rotate` behavior.
+    pub fn set_inertia(&mut self, enabled: bool, tau: f32) {
+        self.inertia_enabled = enabled;
+        self.inertia_tau = tau;
+        if !enabled {
+            self.velocity = Rotor3::identity();
+        }
+    }
     /// Zoom the camera in by some amount. Positive values zoom in, negative zoom out.
     pub fn zoom(&mut self, amount: f32, elapsed: f32) {
         let motion = Vec3::new(0.0, 0.0, amount);
@@ -105,6 +261,60 @@ impl ArcballCamera {
         self.inv_screen[0] = 1.0 / width;
         self.inv_screen[1] = 1.0 / height;
     }
+    /// Get the point the camera orbits around.
+    pub fn center(&self) -> Vec3 {
+        let inv = self.center_translation.inversed();
+        Vec3::new(inv[3].x, inv[3].y, inv[3].z)
+    }
+    /// Move the focus point the camera orbits around to `center`, keeping the current rotation
+    /// and zoom distance.
+    pub fn set_center(&mut self, center: Vec3) {
+        self.center_translation = Mat4::from_translation(center).inversed();
+        self.update_camera();
+    }
+    /// Focus the camera on the axis-aligned bounding box spanned by `min` and `max`: set the
+    /// center to the box's midpoint and pull back along the view axis until the box's bounding
+    /// sphere fits within the vertical field of view `fovy` (in radians).
+    pub fn frame_bounds(&mut self, min: Vec3, max: Vec3, fovy: f32) {
+        self.center_translation = Mat4::from_translation((min + max) * 0.5).inversed();
+        let radius = (max - min).mag() * 0.5;
+        let distance = radius / (fovy * 0.5).sin();
+        self.translation = Mat4::from_translation(Vec3::new(0.0, 0.0, -distance));
+        self.update_camera();
+    }
+    /// Snapshot the camera's current orientation, focus point and zoom distance.
+    pub fn pose(&self) -> CameraPose {
+        CameraPose {
+            rotation: self.rotation,
+            center: self.center(),
+            zoom: self.translation[3].z,
+        }
+    }
+    /// Jump directly to a previously saved [`CameraPose`]. Also resyncs the Turntable elevation
+    /// baseline to the new orientation, so a subsequent Turntable drag clamps relative to where
+    /// this pose actually points rather than wherever `rotation` was pointing before.
+    pub fn set_pose(&mut self, pose: &CameraPose) {
+        self.rotation = pose.rotation;
+        self.center_translation = Mat4::from_translation(pose.center).inversed();
+        self.translation = Mat4::from_translation(Vec3::new(0.0, 0.0, pose.zoom));
+        self.update_camera();
+        self.sync_turntable_pitch();
+    }
+    /// Smoothly move a fraction `t` (`0.0` to `1.0`) of the way from the current pose toward
+    /// `target`: the rotation is slerped, the center and zoom are lerped. Call repeatedly with
+    /// an increasing `t` (e.g. advanced by each frame's elapsed time) for a fly-to transition
+    /// between saved viewpoints. Also resyncs the Turntable elevation baseline, same as
+    /// [`ArcballCamera::set_pose`].
+    pub fn lerp_to(&mut self, target: &CameraPose, t: f32) {
+        let delta = target.rotation * self.rotation.reversed();
+        self.rotation = scale_rotor(delta, t) * self.rotation;
+        let center = self.center() + (target.center - self.center()) * t;
+        self.center_translation = Mat4::from_translation(center).inversed();
+        let zoom = self.translation[3].z + (target.zoom - self.translation[3].z) * t;
+        self.translation = Mat4::from_translation(Vec3::new(0.0, 0.0, zoom));
+        self.update_camera();
+        self.sync_turntable_pitch();
+    }
     fn update_camera(&mut self) {
         self.camera = self.translation
             * Mat3::from(self.rotation).into_homogeneous()
@@ -122,3 +332,26 @@ impl ArcballCamera {
         }
     }
 }
+
+/// The rotation angle (in radians) represented by a rotor, in `[0, 2*PI]`.
+fn rotor_angle(r: Rotor3) -> f32 {
+    let bv_mag = (r.bv.xy * r.bv.xy + r.bv.xz * r.bv.xz + r.bv.yz * r.bv.yz).sqrt();
+    2.0 * f32::atan2(bv_mag, r.s)
+}
+
+/// Scale a rotor's rotation angle by `k`, keeping its rotation plane fixed. Used to turn an
+/// incremental per-frame rotor into a per-second angular velocity (`k = 1 / dt`) and to later
+/// step or decay that velocity by an arbitrary factor.
+fn scale_rotor(r: Rotor3, k: f32) -> Rotor3 {
+    let bv_mag = (r.bv.xy * r.bv.xy + r.bv.xz * r.bv.xz + r.bv.yz * r.bv.yz).sqrt();
+    if bv_mag <= f32::EPSILON {
+        return Rotor3::identity();
+    }
+    let angle = 2.0 * f32::atan2(bv_mag, r.s);
+    let half = angle * k * 0.5;
+    let scale = half.sin() / bv_mag;
+    Rotor3::new(
+        half.cos(),
+        Bivec3::new(r.bv.xy * scale, r.bv.xz * scale, r.bv.yz * scale),
+    )
+}
@@ -0,0 +1,14 @@
+use ultraviolet::{rotor::Rotor3, vec::Vec3};
+
+/// A snapshot of an [`ArcballCamera`](crate::ArcballCamera)'s orientation, focus point and zoom
+/// distance, independent of screen size or zoom speed. Useful for bookmarking viewpoints or
+/// animating between them with [`ArcballCamera::lerp_to`](crate::ArcballCamera::lerp_to).
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CameraPose {
+    pub rotation: Rotor3,
+    pub center: Vec3,
+    /// The view-space Z translation from the center: the zoom distance negated, so negative
+    /// while the camera sits in front of the center.
+    pub zoom: f32,
+}
@@ -0,0 +1,71 @@
+use ultraviolet::{mat::Mat4, projection::perspective_gl, vec::Vec4};
+
+use crate::Camera;
+
+/// Which graphics API's clip space convention a [`Projection`] should target.
+///
+/// OpenGL's clip space Z ranges over `[-1, 1]`, while wgpu (and Vulkan/Metal/D3D) use `[0, 1]`.
+/// Building an OpenGL-style perspective matrix and feeding it directly to a wgpu pipeline silently
+/// maps half the depth range off-screen.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ClipConvention {
+    OpenGl,
+    Wgpu,
+}
+
+/// Converts the `[-1, 1]` OpenGL clip space Z produced by [`perspective_gl`] into the `[0, 1]`
+/// range wgpu (and most other modern APIs) expect.
+#[rustfmt::skip]
+const OPENGL_TO_WGPU_MATRIX: Mat4 = Mat4::new(
+    Vec4::new(1.0, 0.0, 0.0, 0.0),
+    Vec4::new(0.0, 1.0, 0.0, 0.0),
+    Vec4::new(0.0, 0.0, 0.5, 0.0),
+    Vec4::new(0.0, 0.0, 0.5, 1.0),
+);
+
+/// A resize-aware perspective projection, parameterized over the target clip space convention.
+///
+/// Replaces the hand-rolled `perspective_gl(..) * camera.get_mat4()` that call sites used to do
+/// themselves, and gets the depth range right for wgpu-style backends as well as OpenGL.
+pub struct Projection {
+    fovy: f32,
+    aspect: f32,
+    znear: f32,
+    zfar: f32,
+    convention: ClipConvention,
+}
+
+impl Projection {
+    /// Create a new projection. `fovy` is the vertical field of view in radians.
+    pub fn new(
+        fovy: f32,
+        aspect: f32,
+        znear: f32,
+        zfar: f32,
+        convention: ClipConvention,
+    ) -> Projection {
+        Projection {
+            fovy,
+            aspect,
+            znear,
+            zfar,
+            convention,
+        }
+    }
+    /// Update the aspect ratio, e.g. after the window has resized.
+    pub fn resize(&mut self, width: f32, height: f32) {
+        self.aspect = width / height;
+    }
+    /// Compute the perspective projection matrix for the configured clip space convention.
+    pub fn matrix(&self) -> Mat4 {
+        let proj = perspective_gl(self.fovy, self.aspect, self.znear, self.zfar);
+        match self.convention {
+            ClipConvention::OpenGl => proj,
+            ClipConvention::Wgpu => OPENGL_TO_WGPU_MATRIX * proj,
+        }
+    }
+    /// Convenience for the common per-frame `projection * view` multiply.
+    pub fn proj_view(&self, camera: &impl Camera) -> Mat4 {
+        self.matrix() * camera.view()
+    }
+}